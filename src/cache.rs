@@ -0,0 +1,142 @@
+use crate::models::Project;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    fetched_at: u64,
+    projects: Vec<Project>,
+}
+
+/// On-disk cache of a provider's project list, keyed by base URL + scope
+/// (`global` or `group/<id>`), so repeated searches against the same group
+/// don't re-paginate the full project listing every time.
+pub struct ProjectCache {
+    path: PathBuf,
+}
+
+impl ProjectCache {
+    pub fn new(base_url: &str, scope: Option<&str>) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let dir = dirs::cache_dir()
+            .ok_or("Could not determine cache directory")?
+            .join("gitlab-xsearch");
+        std::fs::create_dir_all(&dir)?;
+
+        let scope_key = scope.map(|s| format!("group/{}", s)).unwrap_or_else(|| "global".to_string());
+        let key = sanitize(&format!("{}_{}", base_url, scope_key));
+
+        Ok(Self {
+            path: dir.join(format!("{}.json", key)),
+        })
+    }
+
+    /// Returns the cached projects if a cache entry exists and is newer than `ttl`.
+    pub fn load(&self, ttl: Duration) -> Option<Vec<Project>> {
+        let data = std::fs::read(&self.path).ok()?;
+        let entry: CacheEntry = serde_json::from_slice(&data).ok()?;
+
+        let age = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()?
+            .as_secs()
+            .saturating_sub(entry.fetched_at);
+
+        if Duration::from_secs(age) <= ttl {
+            Some(entry.projects)
+        } else {
+            None
+        }
+    }
+
+    pub fn store(&self, projects: &[Project]) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let entry = CacheEntry {
+            fetched_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            projects: projects.to_vec(),
+        };
+        std::fs::write(&self.path, serde_json::to_vec(&entry)?)?;
+        Ok(())
+    }
+}
+
+fn sanitize(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    /// A cache pointed at a unique file under the OS temp dir, so tests don't
+    /// touch the real cache directory or collide with each other.
+    fn temp_cache() -> ProjectCache {
+        let n = COUNTER.fetch_add(1, Ordering::SeqCst);
+        let path = std::env::temp_dir().join(format!(
+            "gitlab-xsearch-cache-test-{}-{}.json",
+            std::process::id(),
+            n
+        ));
+        ProjectCache { path }
+    }
+
+    fn sample_projects() -> Vec<Project> {
+        vec![Project {
+            id: 1,
+            name: "Test Project".to_string(),
+            path_with_namespace: "group/test-project".to_string(),
+            web_url: "https://gitlab.com/group/test-project".to_string(),
+            http_url_to_repo: "https://gitlab.com/group/test-project.git".to_string(),
+            path: "test-project".to_string(),
+        }]
+    }
+
+    #[test]
+    fn test_store_then_load_round_trips() {
+        let cache = temp_cache();
+        let projects = sample_projects();
+
+        cache.store(&projects).unwrap();
+        let loaded = cache.load(Duration::from_secs(3600)).unwrap();
+
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].id, projects[0].id);
+        assert_eq!(loaded[0].path, projects[0].path);
+
+        std::fs::remove_file(&cache.path).ok();
+    }
+
+    #[test]
+    fn test_load_returns_none_for_stale_entry() {
+        let cache = temp_cache();
+        let stale_entry = CacheEntry {
+            fetched_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                .saturating_sub(3600),
+            projects: sample_projects(),
+        };
+        std::fs::write(&cache.path, serde_json::to_vec(&stale_entry).unwrap()).unwrap();
+
+        assert!(cache.load(Duration::from_secs(60)).is_none());
+
+        std::fs::remove_file(&cache.path).ok();
+    }
+
+    #[test]
+    fn test_load_returns_none_when_no_entry_exists() {
+        let cache = temp_cache();
+        assert!(cache.load(Duration::from_secs(3600)).is_none());
+    }
+
+    #[test]
+    fn test_sanitize_replaces_non_alphanumeric() {
+        assert_eq!(sanitize("https://gitlab.com_group/42"), "https___gitlab_com_group_42");
+    }
+}
@@ -0,0 +1,87 @@
+use crate::models::SearchResultRow;
+
+/// Fuzzy-matches `query` as a (case-insensitive) subsequence of `candidate`,
+/// returning a score that rewards consecutive and early matches, or `None` if
+/// `query` isn't a subsequence of `candidate` at all. An empty query matches
+/// everything with a score of 0.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+
+    let mut score: i64 = 0;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (ci, &ch) in candidate.iter().enumerate() {
+        if qi < query.len() && ch == query[qi] {
+            score += 10;
+            if last_match == Some(ci.wrapping_sub(1)) {
+                score += 15;
+            }
+            if ci == 0 {
+                score += 5;
+            }
+            last_match = Some(ci);
+            qi += 1;
+        }
+    }
+
+    if qi == query.len() { Some(score) } else { None }
+}
+
+/// Fuzzy-matches `query` against a result row's project name, file name, and
+/// snippet, returning the best score across those fields.
+pub fn fuzzy_match_row(query: &str, row: &SearchResultRow) -> Option<i64> {
+    [
+        fuzzy_match(query, &row.project_name),
+        fuzzy_match(query, &row.file_name),
+        fuzzy_match(query, &row.snippet),
+    ]
+    .into_iter()
+    .flatten()
+    .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_match_subsequence() {
+        assert!(fuzzy_match("gxs", "gitlab-xsearch").is_some());
+        assert!(fuzzy_match("zzz", "gitlab-xsearch").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_match_empty_query_matches_everything() {
+        assert_eq!(fuzzy_match("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_fuzzy_match_rewards_consecutive_and_early_matches() {
+        let consecutive = fuzzy_match("git", "gitlab-xsearch").unwrap();
+        let scattered = fuzzy_match("gah", "gitlab-xsearch").unwrap();
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn test_fuzzy_match_row_checks_all_fields() {
+        let row = SearchResultRow {
+            group_path: "group".to_string(),
+            project_name: "my-project".to_string(),
+            project_id: 1,
+            file_name: "src/main.rs".to_string(),
+            line_number: 1,
+            snippet: "fn main() {}".to_string(),
+            clone_url: "https://example.com/group/my-project.git".to_string(),
+            project_folder: "my-project".to_string(),
+        };
+
+        assert!(fuzzy_match_row("main", &row).is_some());
+        assert!(fuzzy_match_row("nonexistent", &row).is_none());
+    }
+}
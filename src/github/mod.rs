@@ -0,0 +1,188 @@
+use crate::models::{GitLabBlobResult, Project};
+use crate::provider::SearchProvider;
+use reqwest::{Client, header};
+use serde::Deserialize;
+use std::error::Error;
+
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+#[derive(Debug, Deserialize)]
+struct GitHubRepo {
+    id: u64,
+    name: String,
+    full_name: String,
+    html_url: String,
+    clone_url: String,
+}
+
+impl From<GitHubRepo> for Project {
+    fn from(repo: GitHubRepo) -> Self {
+        Project {
+            id: repo.id,
+            name: repo.name.clone(),
+            path_with_namespace: repo.full_name,
+            web_url: repo.html_url,
+            http_url_to_repo: repo.clone_url,
+            path: repo.name,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubSearchResponse {
+    items: Vec<GitHubSearchItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubSearchItem {
+    path: String,
+    repository: GitHubRepoRef,
+    #[serde(default)]
+    text_matches: Vec<GitHubTextMatch>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRepoRef {
+    full_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubTextMatch {
+    fragment: String,
+}
+
+pub struct GitHubClient {
+    client: Client,
+    base_url: String,
+}
+
+impl GitHubClient {
+    pub fn new(token: String) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let mut headers = header::HeaderMap::new();
+        let mut auth_value = header::HeaderValue::from_str(&format!("Bearer {}", token))?;
+        auth_value.set_sensitive(true);
+        headers.insert(header::AUTHORIZATION, auth_value);
+        headers.insert(
+            header::ACCEPT,
+            header::HeaderValue::from_static("application/vnd.github+json"),
+        );
+        headers.insert(
+            header::USER_AGENT,
+            header::HeaderValue::from_static("gitlab-xsearch"),
+        );
+
+        let client = Client::builder().default_headers(headers).build()?;
+
+        println!("Using GitHub API at: {}", GITHUB_API_URL);
+
+        Ok(Self {
+            client,
+            base_url: GITHUB_API_URL.to_string(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchProvider for GitHubClient {
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Fetches all repositories based on the scope.
+    /// If `org` is provided, fetches repos for that org via `GET /orgs/{org}/repos`.
+    /// If `org` is None, fetches repos accessible to the user via `GET /user/repos`.
+    async fn get_projects(
+        &self,
+        org: Option<&str>,
+    ) -> Result<Vec<Project>, Box<dyn Error + Send + Sync>> {
+        let mut projects = Vec::new();
+        let mut page = 1;
+
+        let endpoint = if let Some(org) = org {
+            format!("{}/orgs/{}/repos", self.base_url, org)
+        } else {
+            format!("{}/user/repos", self.base_url)
+        };
+
+        loop {
+            let response = self
+                .client
+                .get(&endpoint)
+                .query(&[("per_page", "100"), ("page", &page.to_string())])
+                .send()
+                .await?;
+
+            if !response.status().is_success() {
+                return Err(format!("Failed to fetch repos: {}", response.status()).into());
+            }
+
+            let page_repos: Vec<GitHubRepo> = response.json().await?;
+            if page_repos.is_empty() {
+                break;
+            }
+
+            let fetched = page_repos.len();
+            projects.extend(page_repos.into_iter().map(Project::from));
+
+            if fetched < 100 {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(projects)
+    }
+
+    /// Searches for a query string within a specific repo via `GET /search/code`,
+    /// scoped with the `repo:owner/name` qualifier GitHub requires. `owner/name`
+    /// comes straight from `project.path_with_namespace`, so this works whether
+    /// `project` was just fetched or loaded from the on-disk project cache.
+    async fn search_in_project(
+        &self,
+        project: &Project,
+        query: &str,
+    ) -> Result<Vec<GitLabBlobResult>, Box<dyn Error + Send + Sync>> {
+        let project_id = project.id;
+        let full_name = &project.path_with_namespace;
+
+        let endpoint = format!("{}/search/code", self.base_url);
+        let scoped_query = format!("{} repo:{}", query, full_name);
+
+        let response = self
+            .client
+            .get(&endpoint)
+            .header(
+                header::ACCEPT,
+                "application/vnd.github.v3.text-match+json",
+            )
+            .query(&[("q", scoped_query.as_str())])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "Search failed for project {}: {}",
+                project_id,
+                response.status()
+            )
+            .into());
+        }
+
+        let parsed: GitHubSearchResponse = response.json().await?;
+
+        Ok(parsed
+            .items
+            .into_iter()
+            .map(|item| GitLabBlobResult {
+                filename: item.path,
+                startline: None,
+                project_id,
+                data: item
+                    .text_matches
+                    .first()
+                    .map(|m| m.fragment.clone())
+                    .unwrap_or_else(|| item.repository.full_name.clone()),
+            })
+            .collect())
+    }
+}
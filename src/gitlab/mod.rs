@@ -1,23 +1,56 @@
 use crate::models::{GitLabBlobResult, Project};
+use crate::provider::SearchProvider;
+use crate::ratelimit::{RateLimiter, full_jitter_backoff};
 use reqwest::{Client, header};
 use std::error::Error;
+use std::time::Duration;
+
+/// How the Personal Access Token is presented to the API. `gitlab.com` accepts
+/// either, but some self-hosted instances are configured to only accept one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AuthScheme {
+    Bearer,
+    PrivateToken,
+}
+
+/// Minimum gap enforced between consecutive requests from a single client,
+/// regardless of how many search tasks are running concurrently.
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_millis(100);
+const BACKOFF_BASE: Duration = Duration::from_secs(1);
+const BACKOFF_CAP: Duration = Duration::from_secs(30);
 
 pub struct GitLabClient {
     client: Client,
     base_url: String,
+    limiter: RateLimiter,
 }
 
 impl GitLabClient {
     pub fn new(
         token: String,
         base_url: Option<String>,
+        auth_scheme: AuthScheme,
+        ca_cert_path: Option<String>,
     ) -> Result<Self, Box<dyn Error + Send + Sync>> {
         let mut headers = header::HeaderMap::new();
-        let mut auth_value = header::HeaderValue::from_str(&format!("Bearer {}", token))?;
+        let (header_name, header_value) = match auth_scheme {
+            AuthScheme::Bearer => (header::AUTHORIZATION, format!("Bearer {}", token)),
+            AuthScheme::PrivateToken => (header::HeaderName::from_static("private-token"), token),
+        };
+        let mut auth_value = header::HeaderValue::from_str(&header_value)?;
         auth_value.set_sensitive(true);
-        headers.insert(header::AUTHORIZATION, auth_value);
+        headers.insert(header_name, auth_value);
+
+        let mut builder = Client::builder().default_headers(headers);
+
+        if let Some(path) = ca_cert_path {
+            let pem = std::fs::read(&path)
+                .map_err(|e| format!("Failed to read CA cert at {}: {}", path, e))?;
+            let cert = reqwest::Certificate::from_pem(&pem)?;
+            builder = builder.add_root_certificate(cert);
+        }
 
-        let client = Client::builder().default_headers(headers).build()?;
+        let client = builder.build()?;
 
         let mut base_url = base_url.unwrap_or_else(|| "https://gitlab.com/api/v4".to_string());
 
@@ -32,13 +65,24 @@ impl GitLabClient {
 
         println!("Using GitLab API at: {}", base_url);
 
-        Ok(Self { client, base_url })
+        Ok(Self {
+            client,
+            base_url,
+            limiter: RateLimiter::new(MIN_REQUEST_INTERVAL),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchProvider for GitLabClient {
+    fn base_url(&self) -> &str {
+        &self.base_url
     }
 
     /// Fetches all projects based on the scope.
     /// If `group_id` is provided, fetches projects for that group (and subgroups).
     /// If `group_id` is None, fetches all projects accessible to the user (membership=true).
-    pub async fn get_projects(
+    async fn get_projects(
         &self,
         group_id: Option<&str>,
     ) -> Result<Vec<Project>, Box<dyn Error + Send + Sync>> {
@@ -65,6 +109,7 @@ impl GitLabClient {
                 request.query(&[("membership", "true")])
             };
 
+            self.limiter.acquire().await;
             let response = request.send().await?;
 
             if !response.status().is_success() {
@@ -106,16 +151,18 @@ impl GitLabClient {
     }
 
     /// Searches for a query string within a specific project's blobs.
-    pub async fn search_in_project(
+    async fn search_in_project(
         &self,
-        project_id: u64,
+        project: &Project,
         query: &str,
     ) -> Result<Vec<GitLabBlobResult>, Box<dyn Error + Send + Sync>> {
+        let project_id = project.id;
         let endpoint = format!("{}/projects/{}/search", self.base_url, project_id);
         let mut retry_count = 0;
         let max_retries = 5;
 
         loop {
+            self.limiter.acquire().await;
             let response = self
                 .client
                 .get(&endpoint)
@@ -132,22 +179,25 @@ impl GitLabClient {
                     .into());
                 }
 
+                // `retry-after` is a floor: it pauses every task sharing this client's
+                // limiter, not just this one, so a burst doesn't keep tripping the limit.
                 let wait_time = if let Some(retry_after) = response.headers().get("retry-after") {
                     retry_after
                         .to_str()
-                        .unwrap_or("1")
-                        .parse::<u64>()
-                        .unwrap_or(1)
+                        .ok()
+                        .and_then(|s| s.parse::<u64>().ok())
+                        .map(Duration::from_secs)
+                        .unwrap_or_else(|| full_jitter_backoff(retry_count, BACKOFF_BASE, BACKOFF_CAP))
                 } else {
-                    // Exponential backoff: 2^retry_count
-                    2_u64.pow(retry_count)
+                    full_jitter_backoff(retry_count, BACKOFF_BASE, BACKOFF_CAP)
                 };
 
                 eprintln!(
-                    "\n[429] Rate limited on project {}. Retrying in {}s...",
-                    project_id, wait_time
+                    "\n[429] Rate limited on project {}. Pausing all requests for {:.1}s...",
+                    project_id,
+                    wait_time.as_secs_f64()
                 );
-                tokio::time::sleep(tokio::time::Duration::from_secs(wait_time)).await;
+                self.limiter.pause_for(wait_time).await;
                 retry_count += 1;
                 continue;
             }
@@ -167,4 +217,67 @@ impl GitLabClient {
             return Ok(results);
         }
     }
+
+    /// Calls GitLab's advanced-search endpoint (`scope=blobs`) once per page
+    /// instead of once per project: `/groups/:id/search` when `group_id` is
+    /// given, `/search` otherwise. Requires Advanced Search to be enabled on
+    /// the instance; callers should fall back to per-project search on error.
+    async fn search_aggregate(
+        &self,
+        group_id: Option<&str>,
+        query: &str,
+    ) -> Result<Vec<GitLabBlobResult>, Box<dyn Error + Send + Sync>> {
+        let endpoint = if let Some(gid) = group_id {
+            format!("{}/groups/{}/search", self.base_url, gid)
+        } else {
+            format!("{}/search", self.base_url)
+        };
+
+        let mut results = Vec::new();
+        let mut page = 1;
+
+        loop {
+            self.limiter.acquire().await;
+            let response = self
+                .client
+                .get(&endpoint)
+                .query(&[
+                    ("scope", "blobs"),
+                    ("search", query),
+                    ("per_page", "100"),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .await?;
+
+            if !response.status().is_success() {
+                return Err(format!(
+                    "Aggregate search failed: {} (is Advanced Search enabled on this instance?)",
+                    response.status()
+                )
+                .into());
+            }
+
+            let next_page = response
+                .headers()
+                .get("x-next-page")
+                .and_then(|h| h.to_str().ok())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.to_string());
+
+            let page_results: Vec<GitLabBlobResult> = response.json().await?;
+            if page_results.is_empty() {
+                break;
+            }
+            results.extend(page_results);
+
+            if let Some(next) = next_page {
+                page = next.parse().unwrap_or(page + 1);
+            } else {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
 }
@@ -0,0 +1,113 @@
+use crate::fuzzy::fuzzy_match_row;
+use crate::models::SearchResultRow;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use crossterm::{cursor, execute, terminal};
+use std::error::Error;
+use std::io::{stdout, Stdout};
+
+const VISIBLE_ROWS: usize = 15;
+
+/// Drops into a terminal UI where the user can type to fuzzy-match across
+/// `rows` (project name, file name, snippet) and narrow a live list with the
+/// arrow keys. Returns the selected row, or `None` if the user cancelled
+/// with Esc/Ctrl-C.
+pub fn run_interactive(
+    rows: &[SearchResultRow],
+) -> Result<Option<SearchResultRow>, Box<dyn Error + Send + Sync>> {
+    enable_raw_mode()?;
+    let mut out = stdout();
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    // Run the event loop to completion before tearing the terminal back down,
+    // even if it returns early via `?` (e.g. a `read`/`execute` I/O error) —
+    // otherwise the user's terminal is left in raw mode + the alternate screen.
+    let result = event_loop(&mut out, rows);
+
+    execute!(out, terminal::LeaveAlternateScreen, cursor::Show).ok();
+    disable_raw_mode().ok();
+
+    result
+}
+
+fn event_loop(
+    out: &mut Stdout,
+    rows: &[SearchResultRow],
+) -> Result<Option<SearchResultRow>, Box<dyn Error + Send + Sync>> {
+    let mut query = String::new();
+    let mut selected = 0usize;
+
+    loop {
+        let matches = matching_rows(rows, &query);
+        selected = selected.min(matches.len().saturating_sub(1));
+
+        render(out, &query, &matches, selected)?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Esc => return Ok(None),
+                KeyCode::Enter => {
+                    return Ok(matches.get(selected).map(|(row, _)| (*row).clone()));
+                }
+                KeyCode::Up => selected = selected.saturating_sub(1),
+                KeyCode::Down => selected = (selected + 1).min(matches.len().saturating_sub(1)),
+                KeyCode::Backspace => {
+                    query.pop();
+                    selected = 0;
+                }
+                KeyCode::Char('c')
+                    if key
+                        .modifiers
+                        .contains(crossterm::event::KeyModifiers::CONTROL) =>
+                {
+                    return Ok(None);
+                }
+                KeyCode::Char(c) => {
+                    query.push(c);
+                    selected = 0;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn matching_rows<'a>(
+    rows: &'a [SearchResultRow],
+    query: &str,
+) -> Vec<(&'a SearchResultRow, i64)> {
+    let mut matches: Vec<(&SearchResultRow, i64)> = rows
+        .iter()
+        .filter_map(|row| fuzzy_match_row(query, row).map(|score| (row, score)))
+        .collect();
+    matches.sort_by_key(|m| std::cmp::Reverse(m.1));
+    matches
+}
+
+fn render(
+    out: &mut Stdout,
+    query: &str,
+    matches: &[(&SearchResultRow, i64)],
+    selected: usize,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    execute!(out, terminal::Clear(terminal::ClearType::All), cursor::MoveTo(0, 0))?;
+    println!("Filter: {}\u{2588}\r", query);
+    println!(
+        "{} / {} matches (Enter: select, Esc: cancel)\r",
+        if matches.is_empty() { 0 } else { selected + 1 },
+        matches.len()
+    );
+
+    for (i, (row, _)) in matches.iter().take(VISIBLE_ROWS).enumerate() {
+        let marker = if i == selected { ">" } else { " " };
+        println!(
+            "{} {}/{} {}:{}\r",
+            marker, row.project_name, row.file_name, row.line_number, row.snippet.lines().next().unwrap_or("")
+        );
+    }
+
+    Ok(())
+}
@@ -1,27 +1,56 @@
-mod client;
+mod cache;
+mod fuzzy;
+mod github;
+mod gitlab;
+mod interactive;
 mod models;
+mod provider;
+mod ratelimit;
 
+use cache::ProjectCache;
 use clap::{Parser, Subcommand};
-use client::GitLabClient;
 use comfy_table::{Table, presets::UTF8_FULL};
 use futures::{StreamExt, stream};
-use models::{OutputFormat, SearchResultRow};
+use github::GitHubClient;
+use gitlab::{AuthScheme, GitLabClient};
+use models::{OutputFormat, Project, SearchResultRow};
+use provider::SearchProvider;
+use std::collections::HashMap;
 use std::error::Error;
 use std::io::stdout;
+use std::path::Path;
 use std::sync::Arc;
 
 #[derive(Parser)]
 #[command(name = "gitlab-xsearch")]
 #[command(about = "Transversal search across GitLab projects without cloning", long_about = None)]
 struct Cli {
+    /// Search provider to use
+    #[arg(long, value_enum, default_value_t = Provider::Gitlab)]
+    provider: Provider,
+
     /// GitLab Personal Access Token. Can also be set via GITLAB_TOKEN env var.
     #[arg(long, env = "GITLAB_TOKEN")]
-    token: String,
+    token: Option<String>,
+
+    /// GitHub Personal Access Token. Can also be set via GITHUB_TOKEN env var.
+    #[arg(long, env = "GITHUB_TOKEN")]
+    github_token: Option<String>,
 
     /// GitLab Base URL. Defaults to https://gitlab.com/api/v4
     #[arg(long, env = "GITLAB_URL")]
     url: Option<String>,
 
+    /// How to present the GitLab token: as a Bearer Authorization header or the
+    /// GitLab-native PRIVATE-TOKEN header. Only applies to the gitlab provider.
+    #[arg(long, value_enum, default_value_t = AuthScheme::Bearer)]
+    auth_scheme: AuthScheme,
+
+    /// Path to a PEM file with a custom CA certificate, for self-hosted GitLab
+    /// instances behind a private CA. Only applies to the gitlab provider.
+    #[arg(long)]
+    ca_cert: Option<String>,
+
     /// Search query string
     #[arg(long, short = 'q')]
     query: String,
@@ -34,10 +63,43 @@ struct Cli {
     #[arg(long, short = 'o')]
     output: Option<String>,
 
+    /// After searching, shallow-clone every matching project into this directory
+    /// (skipping folders that already exist)
+    #[arg(long)]
+    clone: Option<String>,
+
+    /// How long a cached project list stays valid, in seconds
+    #[arg(long, default_value_t = 3600)]
+    project_cache_ttl: u64,
+
+    /// Ignore any cached project list and re-fetch from the API
+    #[arg(long)]
+    refresh: bool,
+
+    /// After searching, drop into an interactive fuzzy-filter TUI over the results
+    #[arg(long)]
+    interactive: bool,
+
+    /// Use GitLab's single aggregate search endpoint instead of iterating per
+    /// project (requires Advanced Search on the instance; gitlab provider only).
+    /// Falls back to per-project search automatically if the endpoint errors.
+    #[arg(long, conflicts_with = "per_project")]
+    aggregate: bool,
+
+    /// Iterate per project (default)
+    #[arg(long, conflicts_with = "aggregate")]
+    per_project: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Provider {
+    Gitlab,
+    Github,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Search in all accessible projects
@@ -57,47 +119,15 @@ enum Commands {
     },
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
-    dotenvy::dotenv().ok();
-    let cli = Cli::parse();
-
-    // 1. Initialize Client
-    let client = Arc::new(GitLabClient::new(cli.token, cli.url)?);
-
-    // 2. Fetch Projects Strategy
-    println!("Fetching projects...");
-    let projects = match cli.command {
-        Commands::Global { max } => {
-            let mut p = client.get_projects(None).await?;
-            if let Some(m) = max {
-                println!("Note: Limited to first {} projects", m);
-                p.truncate(m);
-            }
-            p
-        }
-        Commands::Group { id, max } => {
-            let mut p = client.get_projects(Some(&id)).await?;
-            if let Some(m) = max {
-                println!("Note: Limited to first {} projects", m);
-                p.truncate(m);
-            }
-            p
-        }
-    };
-
-    println!(
-        "Found {} projects. Starting search for '{}'...",
-        projects.len(),
-        cli.query
-    );
-
-    // 3. Search Concurrently (Batching)
-    // We use a stream to limit concurrency so we don't hammer the API too hard or run into file descriptor limits
+/// Searches every project individually, with `CONCURRENT_REQUESTS` in flight at
+/// once via `buffer_unordered`, and flattens the results into rows.
+async fn search_per_project(
+    client: &Arc<dyn SearchProvider>,
+    projects: Vec<Project>,
+    query: Arc<String>,
+) -> Result<Vec<SearchResultRow>, Box<dyn Error + Send + Sync>> {
     const CONCURRENT_REQUESTS: usize = 5;
 
-    let query = Arc::new(cli.query);
-    // Initialize progress bar
     let pb = indicatif::ProgressBar::new(projects.len() as u64);
     pb.set_style(indicatif::ProgressStyle::default_bar()
         .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta}) {msg}")?
@@ -105,10 +135,10 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     let results_stream = stream::iter(projects)
         .map(|p| {
-            let client = Arc::clone(&client);
+            let client = Arc::clone(client);
             let query = query.clone();
             tokio::spawn(async move {
-                match client.search_in_project(p.id, &query).await {
+                match client.search_in_project(&p, &query).await {
                     Ok(results) => {
                         if !results.is_empty() {
                             Some(
@@ -130,7 +160,6 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         })
         .buffer_unordered(CONCURRENT_REQUESTS);
 
-    // Collect all results
     let rows: Vec<SearchResultRow> = results_stream
         .inspect(|_| pb.inc(1))
         .filter_map(|res| async {
@@ -149,9 +178,239 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     pb.finish_with_message("Search done");
 
+    Ok(rows)
+}
+
+/// Strips path-traversal and absolute-path components from a server-supplied
+/// path segment before it's joined onto the clone directory. `..` and empty
+/// segments are dropped and any `/`/`\` inside a segment (which would
+/// otherwise let a crafted `project_folder` escape `dir`, or — if absolute —
+/// make `Path::join` discard `dir` entirely) is flattened to `_`.
+fn sanitize_path_segment(s: &str) -> String {
+    let segments: Vec<String> = s
+        .split(['/', '\\'])
+        .filter(|c| !c.is_empty() && *c != ".")
+        .map(|c| if c == ".." { "_".to_string() } else { c.to_string() })
+        .collect();
+    if segments.is_empty() {
+        "_".to_string()
+    } else {
+        segments.join("_")
+    }
+}
+
+/// Deduplicates matched projects by `project_id` and shallow-clones each one
+/// that isn't already present under `dir/<group_path>/<project_folder>`,
+/// using the same `buffer_unordered` concurrency limiting as the search
+/// itself. Projects are keyed (and their clone destination namespaced) by
+/// `group_path`/`project_folder` rather than the bare folder slug, since two
+/// unrelated projects in different groups can share the same slug.
+async fn clone_matching_projects(
+    rows: &[SearchResultRow],
+    dir: &str,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    const CONCURRENT_CLONES: usize = 5;
+
+    let mut targets = HashMap::new();
+    for row in rows {
+        targets.entry(row.project_id).or_insert_with(|| {
+            let rel = Path::new(&sanitize_path_segment(&row.group_path))
+                .join(sanitize_path_segment(&row.project_folder));
+            (row.clone_url.clone(), rel)
+        });
+    }
+
+    std::fs::create_dir_all(dir)?;
+
+    let pb = indicatif::ProgressBar::new(targets.len() as u64);
+    pb.set_style(
+        indicatif::ProgressStyle::default_bar()
+            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} {msg}")?
+            .progress_chars("#>-"),
+    );
+
+    let dir = Arc::new(dir.to_string());
+    let clone_stream = stream::iter(targets.into_values())
+        .map(|(clone_url, rel)| {
+            let dir = Arc::clone(&dir);
+            tokio::spawn(async move {
+                let dest = Path::new(dir.as_str()).join(&rel);
+                let display = rel.display().to_string();
+                if dest.exists() {
+                    eprintln!("Skipping {} (already exists)", display);
+                    return;
+                }
+
+                if let Some(parent) = dest.parent() {
+                    if let Err(e) = std::fs::create_dir_all(parent) {
+                        eprintln!("Failed to create directory for {}: {}", display, e);
+                        return;
+                    }
+                }
+
+                let status = tokio::process::Command::new("git")
+                    .args(["clone", "--depth", "1", &clone_url])
+                    .arg(&dest)
+                    .status()
+                    .await;
+
+                match status {
+                    Ok(s) if s.success() => {}
+                    Ok(s) => eprintln!("git clone for {} exited with {}", display, s),
+                    Err(e) => eprintln!("Failed to clone {}: {}", display, e),
+                }
+            })
+        })
+        .buffer_unordered(CONCURRENT_CLONES);
+
+    clone_stream
+        .inspect(|_| pb.inc(1))
+        .for_each(|res| async move {
+            if let Err(e) = res {
+                eprintln!("Clone task join error: {}", e);
+            }
+        })
+        .await;
+
+    pb.finish_with_message("Clone done");
+
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    dotenvy::dotenv().ok();
+    let cli = Cli::parse();
+
+    // 1. Initialize Client
+    let client: Arc<dyn SearchProvider> = match cli.provider {
+        Provider::Gitlab => {
+            let token = cli
+                .token
+                .ok_or("--token (or GITLAB_TOKEN) is required for the gitlab provider")?;
+            Arc::new(GitLabClient::new(
+                token,
+                cli.url,
+                cli.auth_scheme,
+                cli.ca_cert,
+            )?)
+        }
+        Provider::Github => {
+            let token = cli
+                .github_token
+                .ok_or("--github-token (or GITHUB_TOKEN) is required for the github provider")?;
+            Arc::new(GitHubClient::new(token)?)
+        }
+    };
+
+    // 2. Fetch Projects Strategy
+    let scope: Option<String> = match &cli.command {
+        Commands::Global { .. } => None,
+        Commands::Group { id, .. } => Some(id.clone()),
+    };
+    let max = match cli.command {
+        Commands::Global { max } => max,
+        Commands::Group { max, .. } => max,
+    };
+
+    let cache = ProjectCache::new(client.base_url(), scope.as_deref())?;
+    let ttl = std::time::Duration::from_secs(cli.project_cache_ttl);
+
+    let mut projects = if !cli.refresh {
+        if let Some(cached) = cache.load(ttl) {
+            println!("Using cached project list ({} projects)", cached.len());
+            Some(cached)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+
+    if projects.is_none() {
+        println!("Fetching projects...");
+        let fetched = client.get_projects(scope.as_deref()).await?;
+        cache.store(&fetched)?;
+        projects = Some(fetched);
+    }
+
+    if cli.aggregate && max.is_some() {
+        return Err(
+            "--max is incompatible with --aggregate: the aggregate endpoint always queries \
+             the full scope, so the limit can't be honored. Drop --max or pass --per-project."
+                .into(),
+        );
+    }
+
+    let mut projects = projects.expect("projects is always populated above");
+    if let Some(m) = max {
+        println!("Note: Limited to first {} projects", m);
+        projects.truncate(m);
+    }
+
+    println!(
+        "Found {} projects. Starting search for '{}'...",
+        projects.len(),
+        cli.query
+    );
+
+    // 3. Search (aggregate endpoint when requested, per-project otherwise)
+    if cli.per_project {
+        println!("Note: --per-project explicitly requested; skipping the aggregate endpoint.");
+    }
+    let query = Arc::new(cli.query);
+
+    let rows: Vec<SearchResultRow> = if cli.aggregate {
+        match client.search_aggregate(scope.as_deref(), &query).await {
+            Ok(blobs) => {
+                let projects_by_id: HashMap<u64, &Project> =
+                    projects.iter().map(|p| (p.id, p)).collect();
+                blobs
+                    .iter()
+                    .filter_map(|blob| {
+                        projects_by_id
+                            .get(&blob.project_id)
+                            .map(|p| SearchResultRow::from_api_result(p, blob))
+                    })
+                    .collect()
+            }
+            Err(e) => {
+                eprintln!(
+                    "Aggregate search unavailable ({}); falling back to per-project search",
+                    e
+                );
+                search_per_project(&client, projects, query).await?
+            }
+        }
+    } else {
+        search_per_project(&client, projects, query).await?
+    };
+
     println!("Found {} matches.", rows.len());
 
-    // 4. Output
+    // 4. Interactive fuzzy-filter (optional, takes over from here)
+    if cli.interactive {
+        return match interactive::run_interactive(&rows)? {
+            Some(row) => {
+                println!("{}\t{}", row.clone_url, row.file_name);
+                if let Some(dir) = &cli.clone {
+                    clone_matching_projects(std::slice::from_ref(&row), dir).await?;
+                }
+                Ok(())
+            }
+            None => {
+                println!("No selection made.");
+                Ok(())
+            }
+        };
+    }
+
+    // 5. Clone (optional)
+    if let Some(dir) = &cli.clone {
+        clone_matching_projects(&rows, dir).await?;
+    }
+
+    // 6. Output
     match cli.format {
         OutputFormat::Csv => {
             let mut wtr = if let Some(path) = cli.output {
@@ -0,0 +1,46 @@
+use crate::models::{GitLabBlobResult, Project};
+use std::error::Error;
+
+/// A backend capable of listing projects/repositories and searching code within them.
+///
+/// `GitLabClient` and `GitHubClient` both implement this so `main.rs` can drive the
+/// same project-listing -> concurrent-search -> output pipeline regardless of which
+/// host the user is pointed at. Implementations map their native API responses into
+/// the shared `Project` / `GitLabBlobResult` structs so that `SearchResultRow::from_api_result`
+/// and all `OutputFormat` writers work unchanged.
+#[async_trait::async_trait]
+pub trait SearchProvider: Send + Sync {
+    /// A stable identifier for this provider's endpoint, used as part of the
+    /// project-list cache key so different GitLab instances/orgs don't collide.
+    fn base_url(&self) -> &str;
+
+    /// Fetches all projects in scope. `scope` is a group ID/path for GitLab or an
+    /// org/user login for GitHub; `None` means "everything accessible to the token".
+    async fn get_projects(
+        &self,
+        scope: Option<&str>,
+    ) -> Result<Vec<Project>, Box<dyn Error + Send + Sync>>;
+
+    /// Searches for `query` within a single project's code. Takes the resolved
+    /// `Project` (not just its id) so providers that need more than a numeric
+    /// id to scope a search — e.g. GitHub's `repo:owner/name` qualifier — don't
+    /// have to maintain their own id -> metadata side table populated by a
+    /// prior `get_projects` call, which breaks the moment a cached project
+    /// list lets that call be skipped.
+    async fn search_in_project(
+        &self,
+        project: &Project,
+        query: &str,
+    ) -> Result<Vec<GitLabBlobResult>, Box<dyn Error + Send + Sync>>;
+
+    /// Searches for `query` across every project in `scope` with a single aggregate
+    /// call, when the provider has such an endpoint (GitLab's advanced search).
+    /// Returns an error by default; providers that support it override this.
+    async fn search_aggregate(
+        &self,
+        _scope: Option<&str>,
+        _query: &str,
+    ) -> Result<Vec<GitLabBlobResult>, Box<dyn Error + Send + Sync>> {
+        Err("Aggregate search is not supported by this provider".into())
+    }
+}
@@ -0,0 +1,84 @@
+use rand::Rng;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// A rate limiter shared across every concurrent search task on a client, so a
+/// 429 seen by one task is visible to all of them instead of each task retrying
+/// independently and re-triggering the limit.
+pub struct RateLimiter {
+    min_interval: Duration,
+    last_request_at: Mutex<Option<Instant>>,
+    paused_until: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_request_at: Mutex::new(None),
+            paused_until: Mutex::new(None),
+        }
+    }
+
+    /// Waits out any active global pause and the minimum inter-request
+    /// interval. Call this immediately before sending a request.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let paused = *self.paused_until.lock().await;
+                paused.and_then(|until| {
+                    let now = Instant::now();
+                    (until > now).then(|| until - now)
+                })
+            };
+            match wait {
+                Some(d) => tokio::time::sleep(d).await,
+                None => break,
+            }
+        }
+
+        let mut last = self.last_request_at.lock().await;
+        if let Some(last_at) = *last {
+            let elapsed = last_at.elapsed();
+            if elapsed < self.min_interval {
+                tokio::time::sleep(self.min_interval - elapsed).await;
+            }
+        }
+        *last = Some(Instant::now());
+    }
+
+    /// Pauses every task sharing this limiter for at least `duration`. If a
+    /// longer pause is already in effect, it is left untouched.
+    pub async fn pause_for(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut paused = self.paused_until.lock().await;
+        if paused.map(|p| until > p).unwrap_or(true) {
+            *paused = Some(until);
+        }
+    }
+}
+
+/// Full-jitter exponential backoff: `random(0..=min(cap, base * 2^attempt))`.
+/// Spreads retries out so a burst of concurrent tasks don't all wake up and
+/// hammer the API at the exact same instant.
+pub fn full_jitter_backoff(attempt: u32, base: Duration, cap: Duration) -> Duration {
+    let exp = base.saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX));
+    let capped = exp.min(cap);
+    let millis = capped.as_millis().max(1) as u64;
+    Duration::from_millis(rand::thread_rng().gen_range(0..=millis))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_jitter_backoff_stays_within_cap() {
+        let cap = Duration::from_secs(10);
+        for attempt in 0..10 {
+            let wait = full_jitter_backoff(attempt, Duration::from_secs(1), cap);
+            assert!(wait <= cap);
+        }
+    }
+}